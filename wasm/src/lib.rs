@@ -1,4 +1,6 @@
 use calamine::{open_workbook_from_rs, Reader, Xlsx, Data};
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use encoding_rs::EUC_KR;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -48,6 +50,7 @@ pub struct ValidationRow {
     pub original_total: i32,
     pub original_store_sum: i32,
     pub result: String,
+    pub date_confident: bool,
 }
 
 // 매장별 상세 행
@@ -59,13 +62,21 @@ pub struct StoreDailyRow {
     pub box_sum: i32,
 }
 
+// 매핑 실패 항목 - 원본명과, 찾아낸 경우 가장 가까운 후보("이 이름 맞나요?")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappingFailure {
+    pub original_name: String,
+    pub suggested_name: Option<String>,
+    pub distance: Option<usize>,
+}
+
 // 변환 결과 (JS로 반환)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConversionResult {
     pub data: Vec<DataRow>,
     pub validation: Vec<ValidationRow>,
     pub store_daily: Vec<StoreDailyRow>,
-    pub mapping_failures: Vec<String>,
+    pub mapping_failures: Vec<MappingFailure>,
     pub success: bool,
     pub error: Option<String>,
 }
@@ -79,6 +90,7 @@ struct StoreBlock {
     col_afternoon: u32,
     col_product: u32,
     col_box: u32,
+    product_start_row: u32,
 }
 
 // 매장명 추출
@@ -93,61 +105,107 @@ fn extract_store_name(value: &str) -> Option<String> {
         .map(|m| m.as_str().trim().to_string())
 }
 
-// 파일명에서 날짜 추출
-fn extract_date_from_filename(filename: &str) -> (i32, u32, u32) {
-    let date_re = Regex::new(r"\((\d+)\.(\d+)~(\d+)\.(\d+)\)").unwrap();
-    let year_re = Regex::new(r"(\d+)년\s*(\d+)월").unwrap();
+// 파일명에서 추출한 "시작일~종료일" 날짜 범위
+#[derive(Debug, Clone, Copy)]
+struct DateRange {
+    start: NaiveDate,
+    end: NaiveDate,
+}
 
-    let mut year = 2026i32;
-    let mut month = 1u32;
-    let mut day = 1u32;
+// 파일명에서 날짜 범위를 읽지 못했을 때의 기본값 (과거 구현의 2026-01-01 기본값을 유지).
+// 범위는 있고 연도만 없으면 이 기본값 대신 2026년으로 범위를 해석한다
+fn default_date_range() -> DateRange {
+    let fallback = NaiveDate::from_ymd_opt(2026, 1, 1).expect("2026-01-01 is a valid date");
+    DateRange { start: fallback, end: fallback }
+}
+
+// 파일명에서 "(M.D~M.D)" 범위와 "YYYY년 M월"을 찾아 시작/종료일로 변환한다.
+// 종료일이 시작일보다 달력상 앞서면(예: 12.29~1.2) 연도가 넘어간 것으로 보고
+// 종료일의 연도에 1을 더해 월/연도 경계를 정확히 처리한다
+fn extract_date_range_from_filename(filename: &str) -> Option<DateRange> {
+    let date_re = Regex::new(r"\((\d+)\.(\d+)~(\d+)\.(\d+)\)").ok()?;
+    let year_re = Regex::new(r"(\d+)년\s*(\d+)월").ok()?;
+
+    let date_caps = date_re.captures(filename)?;
+    let start_month: u32 = date_caps.get(1)?.as_str().parse().ok()?;
+    let start_day: u32 = date_caps.get(2)?.as_str().parse().ok()?;
+    let end_month: u32 = date_caps.get(3)?.as_str().parse().ok()?;
+    let end_day: u32 = date_caps.get(4)?.as_str().parse().ok()?;
 
+    let mut year = 2026i32;
     if let Some(year_caps) = year_re.captures(filename) {
-        year = year_caps.get(1).unwrap().as_str().parse().unwrap_or(26);
-        if year < 100 {
-            year += 2000;
-        }
+        let parsed: i32 = year_caps.get(1)?.as_str().parse().ok()?;
+        year = if parsed < 100 { parsed + 2000 } else { parsed };
     }
 
-    if let Some(date_caps) = date_re.captures(filename) {
-        month = date_caps.get(1).unwrap().as_str().parse().unwrap_or(1);
-        day = date_caps.get(2).unwrap().as_str().parse().unwrap_or(1);
-    }
+    let start = NaiveDate::from_ymd_opt(year, start_month, start_day)?;
 
-    (year, month, day)
-}
+    let end_year = if (end_month, end_day) < (start_month, start_day) {
+        year + 1
+    } else {
+        year
+    };
+    let end = NaiveDate::from_ymd_opt(end_year, end_month, end_day)?;
 
-// 날짜 포맷
-fn format_date(year: i32, month: u32, day: u32) -> String {
-    format!("{:04}-{:02}-{:02}", year, month, day)
+    Some(DateRange { start, end })
 }
 
-// 날짜 더하기
-fn add_days(year: i32, month: u32, day: u32, days: u32) -> (i32, u32, u32) {
-    let days_in_month = [0, 31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
-    let mut new_day = day + days;
-    let mut new_month = month;
-    let mut new_year = year;
+// 요일 시트 하나에 배정된 날짜와, 파일명 범위와 실제 시트 구성이 서로 맞는지 여부
+struct InferredDate {
+    date: NaiveDate,
+    confident: bool,
+}
 
-    let is_leap = (new_year % 4 == 0 && new_year % 100 != 0) || (new_year % 400 == 0);
-    let max_days = if new_month == 2 && is_leap {
-        29
-    } else if new_month >= 1 && new_month <= 12 {
-        days_in_month[new_month as usize]
-    } else {
-        31
-    };
+// day_idx는 day_names(월=0..일=6) 배열 안에서의 위치일 뿐, 실제로 존재하는
+// 시트 중 몇 번째인지는 아니다. 날짜는 "첫 번째로 존재하는 요일 시트"를
+// range.start에 고정하고 거기서부터 day_idx 차이만큼 더해 계산한다.
+// 파일명 범위의 길이(일 수)가 실제 시트 개수와 같고, 그 첫 요일이
+// range.start의 실제 요일과도 일치해야만 confident로 본다 - 둘 중 하나라도
+// 어긋나면(주말 포함, 여러 주, 시트 누락, 요일 밀림 등) 불확실 표시를 남겨
+// ValidationRow에서 "날짜 추정됨"으로 드러나게 한다
+fn infer_sheet_date(
+    range: &DateRange,
+    day_idx: u32,
+    first_present_day_idx: u32,
+    present_sheet_count: usize,
+) -> InferredDate {
+    let span_days = (range.end - range.start).num_days().max(0) as usize + 1;
+    let start_weekday_idx = range.start.weekday().num_days_from_monday();
+    let confident = span_days == present_sheet_count && first_present_day_idx == start_weekday_idx;
+
+    let offset = day_idx as i64 - first_present_day_idx as i64;
+    let date = range.start + chrono::Duration::days(offset);
+
+    InferredDate { date, confident }
+}
 
-    while new_day > max_days {
-        new_day -= max_days;
-        new_month += 1;
-        if new_month > 12 {
-            new_month = 1;
-            new_year += 1;
-        }
+// Excel 날짜 일련번호(serial)를 YYYY-MM-DD 문자열로 변환
+fn excel_serial_to_iso_date(serial: f64) -> String {
+    // 1900-02-29는 실제로 존재하지 않는 날짜(엑셀의 버그성 윤년)다.
+    // 상수 25569는 이미 그 버그가 반영된 1970-01-01 기준 serial이라
+    // serial 60 이상은 그대로 써야 하고, 버그가 아직 끼어들지 않은
+    // serial 60 미만만 하루를 더해 보정한다
+    let adjusted = if serial < 60.0 { serial + 1.0 } else { serial };
+    let unix_days = adjusted - 25569.0;
+    let unix_secs = unix_days * 86400.0;
+    let whole_secs = unix_secs.trunc() as i64;
+    let frac_nanos = ((unix_secs - unix_secs.trunc()) * 1_000_000_000.0).round() as u32;
+
+    match NaiveDateTime::from_timestamp_opt(whole_secs, frac_nanos) {
+        Some(dt) => dt.format("%Y-%m-%d").to_string(),
+        None => format!("{}", serial),
     }
+}
 
-    (new_year, new_month, new_day)
+// ISO 형식의 날짜/시간 문자열에서 날짜 부분만 추출
+fn parse_iso_date(s: &str) -> String {
+    if let Ok(dt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S") {
+        return dt.format("%Y-%m-%d").to_string();
+    }
+    if let Ok(d) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return d.format("%Y-%m-%d").to_string();
+    }
+    s.to_string()
 }
 
 // 셀 값을 문자열로
@@ -157,8 +215,8 @@ fn cell_to_string(cell: &Data) -> String {
         Data::Float(f) => format!("{}", f),
         Data::Int(i) => format!("{}", i),
         Data::Bool(b) => format!("{}", b),
-        Data::DateTime(dt) => format!("{}", dt),
-        Data::DateTimeIso(s) => s.clone(),
+        Data::DateTime(serial) => excel_serial_to_iso_date(*serial),
+        Data::DateTimeIso(s) => parse_iso_date(s),
         Data::DurationIso(s) => s.clone(),
         Data::Error(_) => String::new(),
         Data::Empty => String::new(),
@@ -175,6 +233,83 @@ fn cell_to_int(cell: &Data) -> i32 {
     }
 }
 
+// 매장명 정규화 - 공백, 전각/반각 기호, 괄호 안 부가설명, ※/: 장식 제거
+fn normalize_store_name(name: &str) -> String {
+    let paren_re = Regex::new(r"[\(（][^\)）]*[\)）]").unwrap();
+    let without_parens = paren_re.replace_all(name, "");
+
+    without_parens
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '※' && *c != ':' && *c != '：')
+        .map(|c| match c {
+            // 전각 영숫자/기호를 반각으로
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            _ => c,
+        })
+        // 반각으로 바뀐 전각 기호와 원래의 반각 기호를 함께 제거
+        .filter(|c| !c.is_ascii_punctuation())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+// 레벤슈타인 편집 거리
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (len_a, len_b) = (a.len(), b.len());
+
+    let mut dp = vec![vec![0usize; len_b + 1]; len_a + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=len_b {
+        dp[0][j] = j;
+    }
+
+    for i in 1..=len_a {
+        for j in 1..=len_b {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            dp[i][j] = (dp[i - 1][j] + 1)
+                .min(dp[i][j - 1] + 1)
+                .min(dp[i - 1][j - 1] + cost);
+        }
+    }
+
+    dp[len_a][len_b]
+}
+
+// 정규화 후 완전 일치를 먼저 시도하고(distance 0), 실패하면 편집 거리가
+// 가장 작은 후보를 찾는다. 거리가 ≤2이거나 정규화된 이름 길이의 20% 이내면
+// "비슷한 후보"로 인정한다
+fn find_best_match<'a>(
+    store_name: &str,
+    mapping: &'a HashMap<String, MappingEntry>,
+) -> Option<(&'a str, &'a MappingEntry, usize)> {
+    const MAX_ABSOLUTE_DISTANCE: usize = 2;
+    const MAX_RATIO: f64 = 0.2;
+
+    let normalized_target = normalize_store_name(store_name);
+
+    for (key, entry) in mapping {
+        if normalize_store_name(key) == normalized_target {
+            return Some((key.as_str(), entry, 0));
+        }
+    }
+
+    let threshold = ((normalized_target.chars().count() as f64 * MAX_RATIO).floor() as usize)
+        .max(MAX_ABSOLUTE_DISTANCE);
+
+    let mut best: Option<(&str, &MappingEntry, usize)> = None;
+    for (key, entry) in mapping {
+        let distance = levenshtein_distance(&normalized_target, &normalize_store_name(key));
+        if distance <= threshold && best.map_or(true, |(_, _, best_dist)| distance < best_dist) {
+            best = Some((key.as_str(), entry, distance));
+        }
+    }
+
+    best
+}
+
 // 매핑 테이블 파싱
 fn parse_mapping_table(data: &[u8]) -> Result<HashMap<String, MappingEntry>, String> {
     let cursor = Cursor::new(data);
@@ -232,21 +367,123 @@ fn parse_mapping_table(data: &[u8]) -> Result<HashMap<String, MappingEntry>, Str
     Ok(mapping)
 }
 
+// 서브헤더 탐지로 찾아낸 블록의 실제 컬럼 구성
+struct BlockColumns {
+    col_no: u32,
+    col_afternoon: u32,
+    col_product: u32,
+    col_box: u32,
+    product_start_row: u32,
+}
+
+// "※ 매장명" 헤더 아래 몇 줄을 훑어 번호/오후진열/상품명/박스 서브헤더의
+// 실제 컬럼을 찾는다. header_col 좌우로 약간의 탐색창을 두어 좌/우 블록
+// 레이아웃을 모두 같은 방식으로 인식한다. 같은 행에 인접 블록
+// (sibling_header_col)이 있으면 두 헤더 컬럼의 중간 지점에서 탐색창을 잘라
+// 옆 블록의 서브헤더를 자기 것으로 잘못 집어오지 않게 한다
+fn detect_block_columns(
+    range: &calamine::Range<Data>,
+    header_row: u32,
+    header_col: u32,
+    sibling_header_col: Option<u32>,
+) -> Option<BlockColumns> {
+    const SEARCH_WINDOW: i32 = 6;
+    const SUBHEADER_SCAN_ROWS: u32 = 4;
+
+    let header_col_i = header_col as i32;
+    let mut start_col = header_col_i - SEARCH_WINDOW;
+    let mut end_col = header_col_i + SEARCH_WINDOW;
+
+    if let Some(sibling) = sibling_header_col {
+        let midpoint = (header_col_i + sibling as i32) / 2;
+        if sibling as i32 > header_col_i {
+            end_col = end_col.min(midpoint);
+        } else {
+            start_col = start_col.max(midpoint + 1);
+        }
+    }
+
+    let start_col = start_col.max(0) as usize;
+    let end_col = end_col.max(start_col as i32) as usize;
+
+    for row_offset in 1..=SUBHEADER_SCAN_ROWS {
+        let row_idx = header_row + row_offset;
+        let Some(row) = range.rows().nth(row_idx as usize) else {
+            break;
+        };
+
+        let mut col_no = None;
+        let mut col_afternoon = None;
+        let mut col_product = None;
+        let mut col_box = None;
+
+        for (col_idx, cell) in row.iter().enumerate() {
+            if col_idx < start_col || col_idx > end_col {
+                continue;
+            }
+
+            let text = cell_to_string(cell);
+            let trimmed = text.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            if col_no.is_none() && (trimmed.contains('번') && trimmed.contains('호') || trimmed.eq_ignore_ascii_case("no")) {
+                col_no = Some(col_idx as u32);
+            } else if col_afternoon.is_none() && trimmed.contains("오후") && trimmed.contains("진열") {
+                col_afternoon = Some(col_idx as u32);
+            } else if col_product.is_none() && trimmed.contains("상품명") {
+                col_product = Some(col_idx as u32);
+            } else if col_box.is_none() && trimmed.contains("박스") {
+                col_box = Some(col_idx as u32);
+            }
+        }
+
+        if let (Some(col_no), Some(col_product), Some(col_box)) = (col_no, col_product, col_box) {
+            return Some(BlockColumns {
+                col_no,
+                col_afternoon: col_afternoon.unwrap_or(col_no + 1),
+                col_product,
+                col_box,
+                product_start_row: row_idx + 1,
+            });
+        }
+    }
+
+    None
+}
+
 // 매장 블록 찾기
 fn find_store_blocks(range: &calamine::Range<Data>) -> Vec<StoreBlock> {
     let mut blocks = Vec::new();
 
     for (row_idx, row) in range.rows().enumerate() {
+        let row_idx = row_idx as u32;
+
+        // 같은 행의 반대쪽 앵커 컬럼(1/10)에 실제로 매장 블록이 있을 때만
+        // detect_block_columns의 탐색창을 그쪽으로 좁힌다 - 블록이 하나뿐인
+        // 행에서는 서브헤더가 더 멀리 밀려 있어도 그대로 찾을 수 있어야 한다
+        let left_block_present = row.get(1)
+            .map(cell_to_string)
+            .is_some_and(|v| extract_store_name(&v).is_some());
+        let right_block_present = row.get(10)
+            .map(cell_to_string)
+            .is_some_and(|v| extract_store_name(&v).is_some());
+
         if let Some(cell) = row.get(1) {
             let value = cell_to_string(cell);
             if let Some(store_name) = extract_store_name(&value) {
+                // 서브헤더를 못 찾으면 기존의 고정 오프셋으로 폴백한다
+                let sibling = right_block_present.then_some(10);
+                let cols = detect_block_columns(range, row_idx, 1, sibling);
                 blocks.push(StoreBlock {
                     store_name,
-                    row: row_idx as u32,
-                    col_no: 1,
-                    col_afternoon: 2,
-                    col_product: 4,
-                    col_box: 5,
+                    row: row_idx,
+                    col_no: cols.as_ref().map_or(1, |c| c.col_no),
+                    col_afternoon: cols.as_ref().map_or(2, |c| c.col_afternoon),
+                    col_product: cols.as_ref().map_or(4, |c| c.col_product),
+                    col_box: cols.as_ref().map_or(5, |c| c.col_box),
+                    product_start_row: cols.map_or(row_idx + 4, |c| c.product_start_row),
                 });
             }
         }
@@ -254,13 +491,16 @@ fn find_store_blocks(range: &calamine::Range<Data>) -> Vec<StoreBlock> {
         if let Some(cell) = row.get(10) {
             let value = cell_to_string(cell);
             if let Some(store_name) = extract_store_name(&value) {
+                let sibling = left_block_present.then_some(1);
+                let cols = detect_block_columns(range, row_idx, 10, sibling);
                 blocks.push(StoreBlock {
                     store_name,
-                    row: row_idx as u32,
-                    col_no: 10,
-                    col_afternoon: 11,
-                    col_product: 13,
-                    col_box: 14,
+                    row: row_idx,
+                    col_no: cols.as_ref().map_or(10, |c| c.col_no),
+                    col_afternoon: cols.as_ref().map_or(11, |c| c.col_afternoon),
+                    col_product: cols.as_ref().map_or(13, |c| c.col_product),
+                    col_box: cols.as_ref().map_or(14, |c| c.col_box),
+                    product_start_row: cols.map_or(row_idx + 4, |c| c.product_start_row),
                 });
             }
         }
@@ -276,7 +516,7 @@ fn extract_products_from_block(
     max_products: usize,
 ) -> Vec<(String, i32, String)> {
     let mut products = Vec::new();
-    let start_row = block.row as usize + 4;
+    let start_row = block.product_start_row as usize;
 
     for row_idx in start_row..(start_row + max_products) {
         if let Some(row) = range.rows().nth(row_idx) {
@@ -357,6 +597,164 @@ fn get_day_totals(range: &calamine::Range<Data>) -> (i32, i32) {
     (total_box, store_box_sum)
 }
 
+// CSV 출력 인코딩
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CsvEncoding {
+    Utf8,
+    Cp949,
+}
+
+impl CsvEncoding {
+    fn from_str(s: &str) -> CsvEncoding {
+        match s.to_lowercase().as_str() {
+            "cp949" | "euc-kr" | "euckr" => CsvEncoding::Cp949,
+            _ => CsvEncoding::Utf8,
+        }
+    }
+}
+
+// CSV 생성 결과 (data/validation/store_daily 각각 별도 버퍼)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvExport {
+    pub data_csv: Vec<u8>,
+    pub validation_csv: Vec<u8>,
+    pub store_daily_csv: Vec<u8>,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+// 구분자(기본 콤마), 따옴표, 줄바꿈, ※ 등이 섞인 필드를 CSV 규칙에 맞게 감싸기
+fn csv_quote_field(value: &str, delimiter: char) -> String {
+    if value.contains(delimiter)
+        || value.contains('"')
+        || value.contains('\n')
+        || value.contains('\r')
+        || value.contains('※')
+    {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// UTF-8 CSV 텍스트를 지정된 인코딩의 바이트로 변환 (CP949/EUC-KR은 encoding_rs로 트랜스코딩)
+fn encode_csv_text(text: &str, encoding: CsvEncoding) -> Vec<u8> {
+    match encoding {
+        CsvEncoding::Utf8 => text.as_bytes().to_vec(),
+        CsvEncoding::Cp949 => {
+            let (bytes, _, _had_errors) = EUC_KR.encode(text);
+            bytes.into_owned()
+        }
+    }
+}
+
+fn data_rows_to_csv(rows: &[DataRow], delimiter: char) -> String {
+    let sep = delimiter.to_string();
+    let mut out = ["날짜", "코드", "매장명", "상품명", "박스수량", "오후진열"].join(&sep);
+    out.push_str("\r\n");
+
+    for row in rows {
+        let fields = [
+            csv_quote_field(&row.date, delimiter),
+            csv_quote_field(&row.code, delimiter),
+            csv_quote_field(&row.store_name, delimiter),
+            csv_quote_field(&row.product_name, delimiter),
+            row.box_qty.to_string(),
+            csv_quote_field(&row.afternoon, delimiter),
+        ];
+        out.push_str(&fields.join(&sep));
+        out.push_str("\r\n");
+    }
+
+    out
+}
+
+fn validation_rows_to_csv(rows: &[ValidationRow], delimiter: char) -> String {
+    let sep = delimiter.to_string();
+    let mut out = [
+        "날짜",
+        "요일",
+        "추출박스",
+        "원본총계",
+        "원본매장합계",
+        "결과",
+        "날짜확정여부",
+    ]
+    .join(&sep);
+    out.push_str("\r\n");
+
+    for row in rows {
+        let fields = [
+            csv_quote_field(&row.date, delimiter),
+            csv_quote_field(&row.day_name, delimiter),
+            row.extracted_box.to_string(),
+            row.original_total.to_string(),
+            row.original_store_sum.to_string(),
+            csv_quote_field(&row.result, delimiter),
+            if row.date_confident { "확정".to_string() } else { "추정".to_string() },
+        ];
+        out.push_str(&fields.join(&sep));
+        out.push_str("\r\n");
+    }
+
+    out
+}
+
+fn store_daily_rows_to_csv(rows: &[StoreDailyRow], delimiter: char) -> String {
+    let sep = delimiter.to_string();
+    let mut out = ["날짜", "코드", "매장명", "박스합계"].join(&sep);
+    out.push_str("\r\n");
+
+    for row in rows {
+        let fields = [
+            csv_quote_field(&row.date, delimiter),
+            csv_quote_field(&row.code, delimiter),
+            csv_quote_field(&row.store_name, delimiter),
+            row.box_sum.to_string(),
+        ];
+        out.push_str(&fields.join(&sep));
+        out.push_str("\r\n");
+    }
+
+    out
+}
+
+// 변환 결과를 CSV 바이트 버퍼로 반환 (encoding: "utf-8" | "cp949" | "euc-kr")
+#[wasm_bindgen]
+pub fn convert_excel_to_csv(
+    origin_data: &[u8],
+    mapping_data: &[u8],
+    filename: &str,
+    delimiter: &str,
+    encoding: &str,
+) -> JsValue {
+    let result = convert_internal(origin_data, mapping_data, filename);
+
+    if !result.success {
+        let export = CsvExport {
+            data_csv: vec![],
+            validation_csv: vec![],
+            store_daily_csv: vec![],
+            success: false,
+            error: result.error,
+        };
+        return serde_wasm_bindgen::to_value(&export).unwrap_or(JsValue::NULL);
+    }
+
+    let delim = delimiter.chars().next().unwrap_or(',');
+    let enc = CsvEncoding::from_str(encoding);
+
+    let export = CsvExport {
+        data_csv: encode_csv_text(&data_rows_to_csv(&result.data, delim), enc),
+        validation_csv: encode_csv_text(&validation_rows_to_csv(&result.validation, delim), enc),
+        store_daily_csv: encode_csv_text(&store_daily_rows_to_csv(&result.store_daily, delim), enc),
+        success: true,
+        error: None,
+    };
+
+    serde_wasm_bindgen::to_value(&export).unwrap_or(JsValue::NULL)
+}
+
 // 메인 변환 함수 - JSON 결과 반환 (Excel 생성은 JS에서)
 #[wasm_bindgen]
 pub fn convert_excel(
@@ -410,11 +808,23 @@ fn convert_internal(
     let sheet_names = workbook.sheet_names().to_vec();
     console_log!("WASM: Origin loaded - {} sheets", sheet_names.len());
 
-    let day_names = ["월", "화", "수", "목", "금"];
-    let (base_year, base_month, base_day) = extract_date_from_filename(filename);
+    // 주말 시트(토/일)도 후보에 둔다. 요일 이름이 시트명이라 같은 요일을
+    // 두 번 쓰는 복수 주 파일은 여전히 표현할 수 없다 - 그런 파일은
+    // span_days != present_sheet_count가 되어 date_confident: false로
+    // 표시될 뿐, 2주차 이후의 정확한 날짜까지 복구해주지는 않는다
+    let day_names = ["월", "화", "수", "목", "금", "토", "일"];
+    let date_range = extract_date_range_from_filename(filename).unwrap_or_else(default_date_range);
+    let present_sheet_count = day_names
+        .iter()
+        .filter(|name| sheet_names.contains(&name.to_string()))
+        .count();
+    let first_present_day_idx = day_names
+        .iter()
+        .position(|name| sheet_names.contains(&name.to_string()))
+        .unwrap_or(0) as u32;
 
     let mut all_data: Vec<DataRow> = Vec::new();
-    let mut mapping_failures: Vec<String> = Vec::new();
+    let mut mapping_failures: Vec<MappingFailure> = Vec::new();
     let mut validation: Vec<ValidationRow> = Vec::new();
 
     for (day_idx, day_name) in day_names.iter().enumerate() {
@@ -427,8 +837,13 @@ fn convert_internal(
             Err(_) => continue,
         };
 
-        let (year, month, day) = add_days(base_year, base_month, base_day, day_idx as u32);
-        let date_str = format_date(year, month, day);
+        let inferred = infer_sheet_date(
+            &date_range,
+            day_idx as u32,
+            first_present_day_idx,
+            present_sheet_count,
+        );
+        let date_str = inferred.date.format("%Y-%m-%d").to_string();
 
         let blocks = find_store_blocks(&range);
 
@@ -436,10 +851,20 @@ fn convert_internal(
             let (code, system_name) = if let Some(entry) = mapping.get(&block.store_name) {
                 (entry.code.clone(), entry.system_name.clone())
             } else {
-                if !mapping_failures.contains(&block.store_name) {
-                    mapping_failures.push(block.store_name.clone());
+                match find_best_match(&block.store_name, &mapping) {
+                    // 정규화 후 완전 일치 -> 자동 매핑
+                    Some((_, entry, 0)) => (entry.code.clone(), entry.system_name.clone()),
+                    suggestion => {
+                        if !mapping_failures.iter().any(|f| f.original_name == block.store_name) {
+                            mapping_failures.push(MappingFailure {
+                                original_name: block.store_name.clone(),
+                                suggested_name: suggestion.map(|(key, _, _)| key.to_string()),
+                                distance: suggestion.map(|(_, _, distance)| distance),
+                            });
+                        }
+                        ("MAPPING_FAILED".to_string(), format!("[매핑실패] {}", block.store_name))
+                    }
                 }
-                ("MAPPING_FAILED".to_string(), format!("[매핑실패] {}", block.store_name))
             };
 
             let products = extract_products_from_block(&range, block, 25);
@@ -480,6 +905,7 @@ fn convert_internal(
             original_total,
             original_store_sum,
             result,
+            date_confident: inferred.confident,
         });
     }
 
@@ -511,3 +937,150 @@ fn convert_internal(
         error: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calamine::Cell;
+
+    fn range_from_rows(rows: Vec<Vec<Data>>) -> calamine::Range<Data> {
+        let mut cells = Vec::new();
+        for (row_idx, row) in rows.into_iter().enumerate() {
+            for (col_idx, value) in row.into_iter().enumerate() {
+                // (0,0)은 비어 있어도 넣어 Range가 A1에서 시작하게 한다 -
+                // 그래야 rows()의 컬럼 인덱스가 벡터 위치와 같아진다
+                if value != Data::Empty || (row_idx == 0 && col_idx == 0) {
+                    cells.push(Cell::new((row_idx as u32, col_idx as u32), value));
+                }
+            }
+        }
+        calamine::Range::from_sparse(cells)
+    }
+
+    #[test]
+    fn excel_serial_to_iso_date_handles_modern_dates() {
+        assert_eq!(excel_serial_to_iso_date(45658.0), "2025-01-01");
+        assert_eq!(excel_serial_to_iso_date(36526.0), "2000-01-01");
+    }
+
+    #[test]
+    fn excel_serial_to_iso_date_does_not_shift_pre_leap_bug_dates() {
+        assert_eq!(excel_serial_to_iso_date(1.0), "1900-01-01");
+        assert_eq!(excel_serial_to_iso_date(59.0), "1900-02-28");
+    }
+
+    #[test]
+    fn infer_sheet_date_anchors_to_first_present_weekday() {
+        let range = DateRange {
+            start: NaiveDate::from_ymd_opt(2026, 2, 3).unwrap(),
+            end: NaiveDate::from_ymd_opt(2026, 2, 6).unwrap(),
+        };
+        // day_names 배열 기준 화=1, 수=2, 목=3, 금=4가 존재 -> 첫 존재 요일은 1(화)
+        let inferred = infer_sheet_date(&range, 2, 1, 4);
+        assert_eq!(inferred.date, NaiveDate::from_ymd_opt(2026, 2, 4).unwrap());
+        assert!(inferred.confident);
+    }
+
+    #[test]
+    fn infer_sheet_date_flags_unconfident_on_span_mismatch() {
+        let range = DateRange {
+            start: NaiveDate::from_ymd_opt(2026, 2, 2).unwrap(),
+            end: NaiveDate::from_ymd_opt(2026, 2, 6).unwrap(),
+        };
+        let inferred = infer_sheet_date(&range, 0, 0, 4);
+        assert!(!inferred.confident);
+    }
+
+    #[test]
+    fn levenshtein_distance_counts_edits() {
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("abc", "abc"), 0);
+    }
+
+    #[test]
+    fn find_best_match_normalizes_before_comparing() {
+        let mut mapping = HashMap::new();
+        mapping.insert(
+            "강남점".to_string(),
+            MappingEntry { code: "001".to_string(), system_name: "강남점(본사)".to_string() },
+        );
+
+        let (key, _, distance) = find_best_match("강남점 (분점)", &mapping).unwrap();
+        assert_eq!(key, "강남점");
+        assert_eq!(distance, 0);
+    }
+
+    #[test]
+    fn find_best_match_suggests_close_candidate_within_threshold() {
+        let mut mapping = HashMap::new();
+        mapping.insert(
+            "강남점".to_string(),
+            MappingEntry { code: "001".to_string(), system_name: "강남점".to_string() },
+        );
+
+        let (key, _, distance) = find_best_match("강남정", &mapping).unwrap();
+        assert_eq!(key, "강남점");
+        assert_eq!(distance, 1);
+    }
+
+    #[test]
+    fn detect_block_columns_finds_subheader_columns() {
+        let range = range_from_rows(vec![
+            vec![Data::Empty, Data::String("※ 강남점 : 1".to_string())],
+            vec![
+                Data::Empty,
+                Data::String("번호".to_string()),
+                Data::String("오후진열".to_string()),
+                Data::Empty,
+                Data::String("상품명".to_string()),
+                Data::String("박스수".to_string()),
+            ],
+        ]);
+
+        let cols = detect_block_columns(&range, 0, 1, None).unwrap();
+        assert_eq!(cols.col_no, 1);
+        assert_eq!(cols.col_afternoon, 2);
+        assert_eq!(cols.col_product, 4);
+        assert_eq!(cols.col_box, 5);
+        assert_eq!(cols.product_start_row, 2);
+    }
+
+    #[test]
+    fn detect_block_columns_clamps_to_sibling_side() {
+        let header_row = vec![
+            Data::Empty,
+            Data::String("※ 강남점 : 1".to_string()),
+            Data::Empty,
+            Data::Empty,
+            Data::Empty,
+            Data::Empty,
+            Data::Empty,
+            Data::Empty,
+            Data::Empty,
+            Data::Empty,
+            Data::String("※ 서초점 : 1".to_string()),
+        ];
+        let subheader_row = vec![
+            Data::Empty,
+            Data::String("번호".to_string()),
+            Data::String("오후진열".to_string()),
+            Data::Empty,
+            Data::String("상품명".to_string()),
+            Data::String("박스수".to_string()),
+            Data::Empty,
+            Data::Empty,
+            Data::Empty,
+            Data::Empty,
+            Data::String("번호".to_string()),
+            Data::String("오후진열".to_string()),
+            Data::Empty,
+            Data::String("상품명".to_string()),
+            Data::String("박스수".to_string()),
+        ];
+        let range = range_from_rows(vec![header_row, subheader_row]);
+
+        let right_cols = detect_block_columns(&range, 0, 10, Some(1)).unwrap();
+        assert_eq!(right_cols.col_product, 13);
+        assert_eq!(right_cols.col_box, 14);
+    }
+}